@@ -0,0 +1,32 @@
+use std::{path::PathBuf, sync::Arc};
+
+use druid::{Data, Lens};
+
+#[derive(Clone, Copy, PartialEq, Eq, Data, Debug)]
+pub enum SortCriteria {
+    Title,
+    Artist,
+    Album,
+    Duration,
+    DateAdded,
+    Popularity,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Data, Debug)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+#[derive(Clone, Data, Lens)]
+pub struct Config {
+    pub sort_criteria: SortCriteria,
+    pub sort_order: SortOrder,
+    download_dir: Arc<str>,
+}
+
+impl Config {
+    pub fn download_dir(&self) -> PathBuf {
+        PathBuf::from(self.download_dir.as_ref())
+    }
+}
@@ -0,0 +1,51 @@
+use druid::{Data, Lens};
+
+/// Pairs read-mostly context (`C`) with the feature-specific data (`T`) a
+/// widget actually renders, so widgets built for `T` don't need `C` threaded
+/// through their own lenses.
+#[derive(Clone, Data, Lens)]
+pub struct Ctx<C, T> {
+    pub ctx: C,
+    pub data: T,
+}
+
+impl<C, T> Ctx<C, T> {
+    /// Combine a context lens and a data lens into a single `Lens<S, Ctx<C, T>>`.
+    pub fn make<S, L1, L2>(ctx: L1, data: L2) -> impl Lens<S, Ctx<C, T>>
+    where
+        L1: Lens<S, C>,
+        L2: Lens<S, T>,
+        C: Data,
+        T: Data,
+    {
+        MakeCtx { ctx, data }
+    }
+}
+
+struct MakeCtx<L1, L2> {
+    ctx: L1,
+    data: L2,
+}
+
+impl<S, C, T, L1, L2> Lens<S, Ctx<C, T>> for MakeCtx<L1, L2>
+where
+    L1: Lens<S, C>,
+    L2: Lens<S, T>,
+    C: Data,
+    T: Data,
+{
+    fn with<V, F: FnOnce(&Ctx<C, T>) -> V>(&self, data: &S, f: F) -> V {
+        let ctx = self.ctx.with(data, Clone::clone);
+        let inner = self.data.with(data, Clone::clone);
+        f(&Ctx { ctx, data: inner })
+    }
+
+    fn with_mut<V, F: FnOnce(&mut Ctx<C, T>) -> V>(&self, data: &mut S, f: F) -> V {
+        let ctx = self.ctx.with(data, Clone::clone);
+        let inner = self.data.with(data, Clone::clone);
+        let mut combined = Ctx { ctx, data: inner };
+        let result = f(&mut combined);
+        self.data.with_mut(data, |d| *d = combined.data);
+        result
+    }
+}
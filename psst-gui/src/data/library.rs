@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use druid::{
+    im::{HashMap, HashSet, Vector},
+    Data, Lens,
+};
+
+use crate::error::Error;
+
+use super::{Playlist, PlaylistLink, Promise};
+
+#[derive(Clone, Data, Lens)]
+pub struct Library {
+    pub playlists: Promise<(), Vector<Arc<Playlist>>, Error>,
+    downloading_playlists: HashSet<String>,
+    download_progress: HashMap<String, (usize, usize)>,
+}
+
+impl Library {
+    pub fn increment_playlist_track_count(&mut self, link: &PlaylistLink) {
+        self.adjust_playlist_track_count(link, 1);
+    }
+
+    pub fn decrement_playlist_track_count(&mut self, link: &PlaylistLink) {
+        self.adjust_playlist_track_count(link, -1);
+    }
+
+    fn adjust_playlist_track_count(&mut self, link: &PlaylistLink, delta: i64) {
+        if let Promise::Resolved(_, playlists) = &mut self.playlists {
+            if let Some(playlist) = playlists.iter_mut().find(|p| p.id == link.id) {
+                let playlist = Arc::make_mut(playlist);
+                playlist.track_count = (playlist.track_count as i64 + delta).max(0) as usize;
+            }
+        }
+    }
+
+    pub fn begin_playlist_download(&mut self, link: &PlaylistLink) {
+        self.downloading_playlists.insert(link.id.clone());
+    }
+
+    pub fn finish_playlist_download(&mut self, link: &PlaylistLink) {
+        self.downloading_playlists.remove(&link.id);
+        self.download_progress.remove(&link.id);
+    }
+
+    pub fn update_playlist_download_progress(
+        &mut self,
+        link: &PlaylistLink,
+        done: usize,
+        total: usize,
+    ) {
+        self.download_progress.insert(link.id.clone(), (done, total));
+    }
+
+    pub fn download_progress(&self, link: &PlaylistLink) -> Option<(usize, usize)> {
+        self.download_progress.get(&link.id).copied()
+    }
+
+    /// Whether `link` currently has a download in flight, so callers can
+    /// guard against triggering a second one for the same playlist.
+    pub fn is_downloading(&self, link: &PlaylistLink) -> bool {
+        self.downloading_playlists.contains(&link.id)
+    }
+}
@@ -0,0 +1,20 @@
+pub mod config;
+pub mod ctx;
+pub mod library;
+pub mod nav;
+pub mod playlist;
+pub mod promise;
+pub mod state;
+pub mod track;
+
+pub use config::Config;
+pub use ctx::Ctx;
+pub use library::Library;
+pub use nav::Nav;
+pub use playlist::{
+    Image, Playlist, PlaylistAddTrack, PlaylistDetail, PlaylistLink, PlaylistRemoveTrack,
+    PlaylistTracks,
+};
+pub use promise::Promise;
+pub use state::{AppState, CommonCtx};
+pub use track::{SpotifyId, Track, TrackId};
@@ -0,0 +1,12 @@
+use druid::Data;
+
+use super::PlaylistLink;
+
+/// A navigable destination within the app.
+#[derive(Clone, Data, PartialEq, Eq, Debug)]
+pub enum Nav {
+    Home,
+    PlaylistDetail(PlaylistLink),
+    PlaylistRadio(PlaylistLink),
+    BlendDetail(PlaylistLink),
+}
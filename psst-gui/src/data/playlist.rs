@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use druid::{im::Vector, Data, Lens};
+
+use crate::error::Error;
+
+use super::{Promise, Track, TrackId};
+
+#[derive(Clone, Data, Lens, Debug)]
+pub struct Image {
+    pub url: Arc<str>,
+}
+
+#[derive(Clone, Data, Lens, Debug)]
+pub struct Playlist {
+    pub id: String,
+    pub name: Arc<str>,
+    pub description: Arc<str>,
+    pub track_count: usize,
+    pub collaborative: bool,
+    pub image: Option<Image>,
+}
+
+impl Playlist {
+    pub fn link(&self) -> PlaylistLink {
+        PlaylistLink {
+            id: self.id.clone(),
+            name: self.name.clone(),
+        }
+    }
+
+    pub fn url(&self) -> String {
+        format!("https://open.spotify.com/playlist/{}", self.id)
+    }
+
+    pub fn image(&self, _width: f64, _height: f64) -> Option<Image> {
+        self.image.clone()
+    }
+}
+
+#[derive(Clone, Data, Lens, PartialEq, Eq, Hash, Debug)]
+pub struct PlaylistLink {
+    pub id: String,
+    pub name: Arc<str>,
+}
+
+#[derive(Clone, Data, Lens, Debug)]
+pub struct PlaylistTracks {
+    pub id: String,
+    pub name: Arc<str>,
+    pub tracks: Vector<Arc<Track>>,
+}
+
+#[derive(Clone, Data, Lens)]
+pub struct PlaylistDetail {
+    pub tracks: Promise<PlaylistLink, PlaylistTracks, Error>,
+}
+
+#[derive(Clone, Data, Lens, Debug)]
+pub struct PlaylistAddTrack {
+    pub link: PlaylistLink,
+    pub track_id: TrackId,
+}
+
+#[derive(Clone, Data, Lens, Debug)]
+pub struct PlaylistRemoveTrack {
+    pub link: PlaylistLink,
+    pub track_id: TrackId,
+}
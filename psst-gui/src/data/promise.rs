@@ -0,0 +1,39 @@
+use druid::Data;
+
+use crate::error::Error;
+
+/// The state of a value fetched asynchronously, keyed by the request (`D`)
+/// that produced it.
+///
+/// Carrying `D` alongside the result lets a late-arriving response for a
+/// stale request be told apart from the one currently on screen.
+#[derive(Clone, Data)]
+pub enum Promise<D, T, E = Error> {
+    Empty,
+    Deferred(D),
+    Resolved(D, T),
+    Rejected(D, E),
+}
+
+impl<D, T, E> Promise<D, T, E> {
+    pub fn defer(&mut self, request: D) {
+        *self = Self::Deferred(request);
+    }
+
+    pub fn update(&mut self, (request, result): (D, Result<T, E>)) {
+        *self = match result {
+            Ok(value) => Self::Resolved(request, value),
+            Err(err) => Self::Rejected(request, err),
+        };
+    }
+
+    pub fn resolve(&mut self, request: D, value: T) {
+        *self = Self::Resolved(request, value);
+    }
+}
+
+impl<D: Default, T, E> Default for Promise<D, T, E> {
+    fn default() -> Self {
+        Self::Empty
+    }
+}
@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use druid::{im::Vector, Data, Lens};
+
+use crate::error::Error;
+
+use super::{Config, Library, PlaylistDetail, PlaylistLink, Promise, Track};
+
+/// Read-mostly context shared by several detail views (e.g. the current
+/// library contents: its playlists and their download state).
+#[derive(Clone, Data, Lens)]
+pub struct CommonCtx {
+    pub library: Arc<Library>,
+}
+
+#[derive(Clone, Data, Lens)]
+pub struct AppState {
+    pub config: Config,
+    pub library: Arc<Library>,
+    pub common_ctx: CommonCtx,
+    pub playlist_detail: PlaylistDetail,
+    pub blend_detail: PlaylistDetail,
+    pub playlist_radio: Promise<PlaylistLink, Vector<Arc<Track>>, Error>,
+    radio_link: Option<PlaylistLink>,
+    radio_queue: Vector<Arc<Track>>,
+    /// Playlists picked via "Select for Merge/Blend", in selection order, so
+    /// [`crate::ui::playlist::MERGE_PLAYLISTS`] and
+    /// [`crate::ui::playlist::BLEND_PLAYLISTS`] have something to act on.
+    pub selected_playlists: Vector<PlaylistLink>,
+    alerts: Vector<Arc<str>>,
+}
+
+impl AppState {
+    pub fn with_library_mut<V>(&mut self, f: impl FnOnce(&mut Library) -> V) -> V {
+        let result = f(Arc::make_mut(&mut self.library));
+        self.common_ctx.library = self.library.clone();
+        result
+    }
+
+    pub fn error_alert(&mut self, err: Error) {
+        self.alerts.push_back(format!("{err:?}").into());
+    }
+
+    pub fn info_alert(&mut self, message: &str) {
+        self.alerts.push_back(message.into());
+    }
+
+    /// Make a freshly generated radio queue the current one, replacing any
+    /// previous queue. This does not start playback by itself.
+    pub fn queue_radio(&mut self, link: PlaylistLink, tracks: Vector<Arc<Track>>) {
+        self.radio_link = Some(link);
+        self.radio_queue = tracks;
+    }
+
+    /// Append another batch of recommendations to the current radio queue,
+    /// keeping [`AppState::playlist_radio`] (what `radio_detail_widget` shows)
+    /// in step with it.
+    pub fn extend_radio_queue(&mut self, link: PlaylistLink, tracks: Vector<Arc<Track>>) {
+        if self.radio_link.as_ref() != Some(&link) {
+            return;
+        }
+        self.radio_queue.extend(tracks.iter().cloned());
+        if let Promise::Resolved(resolved_link, resolved_tracks) = &mut self.playlist_radio {
+            if *resolved_link == link {
+                resolved_tracks.extend(tracks);
+            }
+        }
+    }
+
+    /// Pop the next track off the radio queue as playback consumes it.
+    pub fn advance_radio_queue(&mut self) {
+        self.radio_queue.pop_front();
+    }
+
+    /// Returns the radio playlist's link once its queue has fewer than
+    /// `margin` tracks left, so the caller knows to ask for more.
+    pub fn radio_queue_needs_refill(&self, margin: usize) -> Option<PlaylistLink> {
+        if self.radio_queue.len() < margin {
+            self.radio_link.clone()
+        } else {
+            None
+        }
+    }
+
+    /// Add or remove `link` from the merge/blend selection.
+    pub fn toggle_playlist_selected(&mut self, link: PlaylistLink) {
+        if let Some(pos) = self.selected_playlists.iter().position(|l| *l == link) {
+            self.selected_playlists.remove(pos);
+        } else {
+            self.selected_playlists.push_back(link);
+        }
+    }
+
+    pub fn is_playlist_selected(&self, link: &PlaylistLink) -> bool {
+        self.selected_playlists.iter().any(|l| l == link)
+    }
+
+    /// Download progress for the playlist currently shown in the detail view.
+    pub fn current_playlist_download_progress(&self) -> Option<(usize, usize)> {
+        let link = match &self.playlist_detail.tracks {
+            Promise::Deferred(link) => link,
+            Promise::Resolved(link, _) => link,
+            Promise::Rejected(link, _) => link,
+            Promise::Empty => return None,
+        };
+        self.library.download_progress(link)
+    }
+}
@@ -0,0 +1,87 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::{DateTime, Utc};
+use druid::{im::Vector, Data, Lens};
+
+/// A Spotify catalog id.
+///
+/// Only track ids are needed by the playlist feature, so this is kept to the
+/// handful of accessors callers actually use; see the id-handling in
+/// `webapi` for how ids reach this module.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct SpotifyId(pub u64);
+
+impl SpotifyId {
+    pub fn to_base62(&self) -> String {
+        const ALPHABET: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+        let mut n = self.0;
+        let mut digits = Vec::new();
+        loop {
+            digits.push(ALPHABET[(n % 62) as usize]);
+            n /= 62;
+            if n == 0 {
+                break;
+            }
+        }
+        digits.reverse();
+        String::from_utf8(digits).expect("base62 alphabet is ASCII")
+    }
+
+    pub fn to_uri(&self) -> Option<String> {
+        Some(format!("spotify:track:{}", self.to_base62()))
+    }
+}
+
+impl Data for SpotifyId {
+    fn same(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct TrackId(pub SpotifyId);
+
+impl Data for TrackId {
+    fn same(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+#[derive(Clone, Lens, Debug)]
+pub struct Track {
+    pub id: TrackId,
+    pub name: Arc<str>,
+    pub artists: Vector<Arc<str>>,
+    pub album_name: Arc<str>,
+    pub duration: Duration,
+    /// When this track was added to the playlist it was loaded from, used by
+    /// [`crate::data::config::SortCriteria::DateAdded`].
+    pub added_at: DateTime<Utc>,
+    /// Spotify's 0-100 popularity score, used by
+    /// [`crate::data::config::SortCriteria::Popularity`].
+    pub popularity: u32,
+}
+
+impl Track {
+    pub fn artist_name(&self) -> Arc<str> {
+        self.artists
+            .iter()
+            .map(|a| a.as_ref())
+            .collect::<Vec<_>>()
+            .join(", ")
+            .into()
+    }
+
+    pub fn album_name(&self) -> Arc<str> {
+        self.album_name.clone()
+    }
+}
+
+// `Data` is implemented by hand rather than derived: `DateTime<Utc>` doesn't
+// implement it, and identity comparison by id is the usual shortcut for
+// list items like this one anyway.
+impl Data for Track {
+    fn same(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
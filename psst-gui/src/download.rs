@@ -0,0 +1,75 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use druid::ExtEventSink;
+use once_cell::sync::OnceCell;
+
+use crate::{
+    data::{Config, PlaylistLink},
+    error::Error,
+    player::Player,
+    ui::playlist::DOWNLOAD_TRACK_PROGRESS,
+    webapi::WebApi,
+};
+
+static INSTANCE: OnceCell<Arc<Downloader>> = OnceCell::new();
+
+/// Downloads whole playlists for offline listening.
+///
+/// Tracks are resolved through [`WebApi::get_playlist_tracks`], fetched through
+/// the same playback/decrypt path used for streaming, and written as encrypted
+/// OGG files into a configurable directory. Already-present tracks are skipped,
+/// so an interrupted download resumes where it left off.
+pub struct Downloader {
+    directory: PathBuf,
+    sink: ExtEventSink,
+}
+
+impl Downloader {
+    pub fn install(config: &Config, sink: ExtEventSink) {
+        let downloader = Arc::new(Self {
+            directory: config.download_dir(),
+            sink,
+        });
+        INSTANCE.set(downloader).ok();
+    }
+
+    pub fn global() -> Arc<Self> {
+        INSTANCE
+            .get()
+            .expect("Downloader has not been installed")
+            .clone()
+    }
+
+    /// Download every track of `link` into the configured directory.
+    ///
+    /// Tracks that already have a file on disk are skipped, which lets a
+    /// partially-downloaded playlist resume instead of starting over. After
+    /// each track, whether written or skipped, a [`DOWNLOAD_TRACK_PROGRESS`]
+    /// command is submitted so the UI can report how far along the download is.
+    pub fn download_playlist(&self, link: &PlaylistLink) -> Result<(), Error> {
+        let target = self.directory.join(link.id.as_str());
+        fs::create_dir_all(&target)?;
+
+        let tracks = WebApi::global().get_playlist_tracks(&link.id)?;
+        let total = tracks.len();
+        for (done, track) in tracks.iter().enumerate() {
+            let path = self.track_path(&target, &track.id.0.to_base62());
+            if !path.exists() {
+                let audio = Player::global().get_track_audio(&track.id)?;
+                fs::write(&path, audio)?;
+            }
+            self.sink
+                .submit_command(DOWNLOAD_TRACK_PROGRESS, (link.clone(), done + 1, total), druid::Target::Auto)
+                .ok();
+        }
+        Ok(())
+    }
+
+    fn track_path(&self, dir: &Path, id: &str) -> PathBuf {
+        dir.join(format!("{id}.ogg"))
+    }
+}
@@ -14,6 +14,7 @@ use crate::{
         AppState, Ctx, Library, Nav, Playlist, PlaylistAddTrack, PlaylistDetail, PlaylistLink,
         PlaylistRemoveTrack, PlaylistTracks, Track,
     },
+    download::Downloader,
     error::Error,
     webapi::WebApi,
     widget::{Async, MyWidgetExt, RemoteImage},
@@ -26,82 +27,278 @@ pub const LOAD_DETAIL: Selector<(PlaylistLink, AppState)> =
     Selector::new("app.playlist.load-detail");
 pub const ADD_TRACK: Selector<PlaylistAddTrack> = Selector::new("app.playlist.add-track");
 pub const REMOVE_TRACK: Selector<PlaylistRemoveTrack> = Selector::new("app.playlist.remove-track");
+pub const DOWNLOAD_PLAYLIST: Selector<PlaylistLink> = Selector::new("app.playlist.download");
+/// Reports how many of a playlist's tracks have been downloaded so far.
+///
+/// Submitted by [`crate::download::Downloader`] from the background thread
+/// after every track it writes or skips as already present, so the detail
+/// view can show live progress.
+pub const DOWNLOAD_TRACK_PROGRESS: Selector<(PlaylistLink, usize, usize)> =
+    Selector::new("app.playlist.download-track-progress");
+pub const PLAYLIST_RADIO: Selector<PlaylistLink> = Selector::new("app.playlist.radio");
+
+/// Spotify's recommendations endpoint accepts at most five seeds.
+const MAX_RADIO_SEEDS: usize = 5;
+
+/// How many tracks to request per radio batch.
+const RADIO_QUEUE_LEN: usize = 50;
+
+/// Once fewer than this many tracks remain in the radio queue,
+/// [`PLAYLIST_RADIO_REFILL`] requests another batch so playback never stops.
+const RADIO_REFILL_MARGIN: usize = 10;
+
+/// Appends another batch of recommendations to the radio queue once it nears
+/// the end.
+///
+/// Submitted from the `QUEUE_ADVANCED` handler only when fewer than
+/// [`RADIO_REFILL_MARGIN`] tracks are left; the handler always requests a new
+/// batch, seeded from the last tracks of the source playlist.
+pub const PLAYLIST_RADIO_REFILL: Selector<PlaylistLink> =
+    Selector::new("app.playlist.radio-refill");
+
+pub const MERGE_PLAYLISTS: Selector<Vector<PlaylistLink>> =
+    Selector::new("app.playlist.merge");
+pub const BLEND_PLAYLISTS: Selector<(PlaylistLink, PlaylistLink)> =
+    Selector::new("app.playlist.blend");
+/// Adds or removes a playlist from [`AppState::selected_playlists`], the pool
+/// [`MERGE_PLAYLISTS`] and [`BLEND_PLAYLISTS`] are driven from.
+pub const TOGGLE_PLAYLIST_SELECTED: Selector<PlaylistLink> =
+    Selector::new("app.playlist.toggle-selected");
+
+/// Upper bound on the number of tracks in a blended playlist.
+const BLEND_MAX_LEN: usize = 100;
+
+/// How many [`WebApi::add_track_to_playlist`] calls to group per batch when
+/// populating a merged or blended playlist.
+const ADD_TRACK_BATCH: usize = 100;
+
+/// Shows links that act on [`AppState::selected_playlists`]. The links are
+/// always visible; a click does nothing unless there are enough playlists
+/// selected to merge (2+) or blend (exactly 2).
+fn selection_toolbar_widget() -> impl Widget<AppState> {
+    Flex::row()
+        .with_child(
+            Label::dynamic(|data: &AppState, _| {
+                format!("Merge {} Selected", data.selected_playlists.len())
+            })
+            .with_text_size(theme::TEXT_SIZE_SMALL)
+            .link()
+            .on_click(|ctx, data: &mut AppState, _| {
+                if data.selected_playlists.len() >= 2 {
+                    ctx.submit_command(MERGE_PLAYLISTS.with(data.selected_playlists.clone()));
+                }
+            }),
+        )
+        .with_default_spacer()
+        .with_child(
+            Label::dynamic(|data: &AppState, _| {
+                format!("Blend {} Selected", data.selected_playlists.len())
+            })
+            .with_text_size(theme::TEXT_SIZE_SMALL)
+            .link()
+            .on_click(|ctx, data: &mut AppState, _| {
+                if let [a, b] = &data.selected_playlists.iter().cloned().collect::<Vec<_>>()[..] {
+                    ctx.submit_command(BLEND_PLAYLISTS.with((a.clone(), b.clone())));
+                }
+            }),
+        )
+        .padding(Insets::uniform_xy(theme::grid(2.0), theme::grid(0.6)))
+}
 
 pub fn list_widget() -> impl Widget<AppState> {
-    Async::new(
-        utils::spinner_widget,
-        || {
-            List::new(|| {
-                Label::raw()
-                    .with_line_break_mode(LineBreaking::WordWrap)
-                    .with_text_size(theme::TEXT_SIZE_SMALL)
-                    .lens(Playlist::name)
-                    .expand_width()
-                    .padding(Insets::uniform_xy(theme::grid(2.0), theme::grid(0.6)))
-                    .link()
-                    .on_click(|ctx, playlist, _| {
-                        ctx.submit_command(
-                            cmd::NAVIGATE.with(Nav::PlaylistDetail(playlist.link())),
-                        );
+    Flex::column()
+        .with_child(selection_toolbar_widget())
+        .with_flex_child(
+            Async::new(
+                utils::spinner_widget,
+                || {
+                    List::new(|| {
+                        Label::raw()
+                            .with_line_break_mode(LineBreaking::WordWrap)
+                            .with_text_size(theme::TEXT_SIZE_SMALL)
+                            .lens(Playlist::name)
+                            .expand_width()
+                            .padding(Insets::uniform_xy(theme::grid(2.0), theme::grid(0.6)))
+                            .link()
+                            .on_click(|ctx, playlist, _| {
+                                ctx.submit_command(
+                                    cmd::NAVIGATE.with(Nav::PlaylistDetail(playlist.link())),
+                                );
+                            })
+                            .context_menu(playlist_menu)
                     })
-                    .context_menu(playlist_menu)
-            })
-        },
-        utils::error_widget,
-    )
-    .lens(AppState::library.then(Library::playlists.in_arc()))
-    .on_command_async(
-        LOAD_LIST,
-        |_| WebApi::global().get_playlists(),
-        |_, data, d| data.with_library_mut(|l| l.playlists.defer(d)),
-        |_, data, r| data.with_library_mut(|l| l.playlists.update(r)),
-    )
-    .on_command_async(
-        ADD_TRACK,
-        |d| {
-            WebApi::global().add_track_to_playlist(
-                &d.link.id,
-                &d.track_id
-                    .0
-                    .to_uri()
-                    .ok_or_else(|| Error::WebApiError("Item doesn't have URI".to_string()))?,
-            )
-        },
-        |_, data, d| {
-            data.with_library_mut(|library| library.increment_playlist_track_count(&d.link))
-        },
-        |_, data, (_, r)| {
-            if let Err(err) = r {
-                data.error_alert(err);
-            } else {
-                data.info_alert("Added to playlist.");
-            }
-        },
-    )
-    .on_command_async(
-        REMOVE_TRACK,
-        |d| {
-            WebApi::global().remove_track_from_playlist(
-                &d.link.id,
-                &d.track_id
-                    .0
-                    .to_uri()
-                    .ok_or_else(|| Error::WebApiError("Item doesn't have URI".to_string()))?,
+                },
+                utils::error_widget,
             )
-        },
-        |_, data, d| {
-            data.with_library_mut(|library| library.decrement_playlist_track_count(&d.link))
-        },
-        |e, data, (p, r)| {
-            if let Err(err) = r {
-                data.error_alert(err);
-            } else {
-                data.info_alert("Removed from playlist.");
+            .lens(AppState::library.then(Library::playlists.in_arc())),
+            1.0,
+        )
+        .on_command_async(
+            LOAD_LIST,
+            |_| WebApi::global().get_playlists(),
+            |_, data, d| data.with_library_mut(|l| l.playlists.defer(d)),
+            |_, data, r| data.with_library_mut(|l| l.playlists.update(r)),
+        )
+        .on_command_async(
+            ADD_TRACK,
+            |d| {
+                WebApi::global().add_track_to_playlist(
+                    &d.link.id,
+                    &d.track_id
+                        .0
+                        .to_uri()
+                        .ok_or_else(|| Error::WebApiError("Item doesn't have URI".to_string()))?,
+                )
+            },
+            |_, data, d| {
+                data.with_library_mut(|library| library.increment_playlist_track_count(&d.link))
+            },
+            |_, data, (_, r)| {
+                if let Err(err) = r {
+                    data.error_alert(err);
+                } else {
+                    data.info_alert("Added to playlist.");
+                }
+            },
+        )
+        .on_command_async(
+            REMOVE_TRACK,
+            |d| {
+                WebApi::global().remove_track_from_playlist(
+                    &d.link.id,
+                    &d.track_id
+                        .0
+                        .to_uri()
+                        .ok_or_else(|| Error::WebApiError("Item doesn't have URI".to_string()))?,
+                )
+            },
+            |_, data, d| {
+                data.with_library_mut(|library| library.decrement_playlist_track_count(&d.link))
+            },
+            |e, data, (p, r)| {
+                if let Err(err) = r {
+                    data.error_alert(err);
+                } else {
+                    data.info_alert("Removed from playlist.");
+                }
+                // Re-submit the `LOAD_DETAIL` command to reload the playlist data.
+                e.submit_command(LOAD_DETAIL.with((p.link, data.clone())))
+            },
+        )
+        .on_command_async(
+            DOWNLOAD_PLAYLIST,
+            |link| Downloader::global().download_playlist(&link),
+            |_, data, link| data.with_library_mut(|l| l.begin_playlist_download(&link)),
+            |_, data, (link, r)| {
+                if let Err(err) = r {
+                    data.error_alert(err);
+                } else {
+                    data.info_alert("Playlist downloaded for offline listening.");
+                }
+                data.with_library_mut(|l| l.finish_playlist_download(&link));
+            },
+        )
+        .on_command(DOWNLOAD_TRACK_PROGRESS, |_, (link, done, total), data| {
+            data.with_library_mut(|l| l.update_playlist_download_progress(link, *done, *total));
+        })
+        .on_command_async(
+            PLAYLIST_RADIO,
+            |link| {
+                let tracks = WebApi::global().get_playlist_tracks(&link.id)?;
+                let seeds: Vec<_> = tracks
+                    .iter()
+                    .take(MAX_RADIO_SEEDS)
+                    .map(|track| track.id)
+                    .collect();
+                WebApi::global().get_recommendations(&seeds, RADIO_QUEUE_LEN)
+            },
+            |_, data, link| {
+                data.playlist_radio.defer(link);
+            },
+            |e, data, (link, r)| {
+                data.playlist_radio.update((link.clone(), r.clone()));
+                match r {
+                    Ok(tracks) => {
+                        data.queue_radio(link.clone(), tracks);
+                        e.submit_command(cmd::NAVIGATE.with(Nav::PlaylistRadio(link)));
+                    }
+                    Err(err) => data.error_alert(err),
+                }
+            },
+        )
+        .on_command_async(
+            PLAYLIST_RADIO_REFILL,
+            |link| {
+                // Seed the next batch from the last tracks of the source
+                // playlist rather than its first ones. Every refill uses the
+                // same seeds; the queue's own tracks are not used as seeds.
+                let tracks = WebApi::global().get_playlist_tracks(&link.id)?;
+                let seeds: Vec<_> = tracks
+                    .iter()
+                    .rev()
+                    .take(MAX_RADIO_SEEDS)
+                    .map(|track| track.id)
+                    .collect();
+                WebApi::global().get_recommendations(&seeds, RADIO_QUEUE_LEN)
+            },
+            |_, _, _| {},
+            |_, data, (link, r)| match r {
+                Ok(tracks) => data.extend_radio_queue(link, tracks),
+                Err(err) => data.error_alert(err),
+            },
+        )
+        .on_command(cmd::QUEUE_ADVANCED, |ctx, _, data| {
+            // Track consumption before checking the margin, or the queue (which
+            // only ever grows via `queue_radio`/`extend_radio_queue`) would never
+            // dip back below it after the first fill.
+            data.advance_radio_queue();
+            if let Some(link) = data.radio_queue_needs_refill(RADIO_REFILL_MARGIN) {
+                ctx.submit_command(PLAYLIST_RADIO_REFILL.with(link));
             }
-            // Re-submit the `LOAD_DETAIL` command to reload the playlist data.
-            e.submit_command(LOAD_DETAIL.with((p.link, data.clone())))
-        },
-    )
+        })
+        .on_command_async(
+            MERGE_PLAYLISTS,
+            |links| merge_playlists(&links),
+            |_, _, _| {},
+            |e, data, (_, r)| match r {
+                Ok(playlist) => {
+                    data.info_alert("Merged playlists.");
+                    data.selected_playlists.clear();
+                    e.submit_command(cmd::NAVIGATE.with(Nav::PlaylistDetail(playlist.link())));
+                    e.submit_command(LOAD_LIST);
+                }
+                Err(err) => data.error_alert(err),
+            },
+        )
+        .on_command(TOGGLE_PLAYLIST_SELECTED, |_, link, data| {
+            data.toggle_playlist_selected(link.clone());
+        })
+        .on_command_async(
+            BLEND_PLAYLISTS,
+            |links| blend_playlists(&links),
+            |_, _, _| {},
+            |e, data, (_, r)| match r {
+                Ok((playlist, tracks)) => {
+                    data.info_alert("Blended playlists.");
+                    data.selected_playlists.clear();
+                    let link = playlist.link();
+                    // Resolve `blend_detail` directly from what `blend_playlists`
+                    // already computed, mirroring how `PLAYLIST_RADIO` resolves
+                    // `playlist_radio` in its own success handler above.
+                    data.blend_detail.tracks.resolve(
+                        link.clone(),
+                        PlaylistTracks {
+                            id: link.id.clone(),
+                            name: link.name.clone(),
+                            tracks,
+                        },
+                    );
+                    e.submit_command(cmd::NAVIGATE.with(Nav::BlendDetail(link)));
+                    e.submit_command(LOAD_LIST);
+                }
+                Err(err) => data.error_alert(err),
+            },
+        )
 }
 
 pub fn playlist_widget() -> impl Widget<Playlist> {
@@ -152,7 +349,68 @@ fn rounded_cover_widget(size: f64) -> impl Widget<Playlist> {
     cover_widget(size).clip(Size::new(size, size).to_rounded_rect(4.0))
 }
 
+/// Shows "Downloading: n/total" for the playlist currently on screen, or
+/// nothing once the download has finished (or none is in progress).
+fn download_progress_widget() -> impl Widget<AppState> {
+    Label::dynamic(|data: &AppState, _| match data.current_playlist_download_progress() {
+        Some((done, total)) => format!("Downloading: {done}/{total}"),
+        None => String::new(),
+    })
+    .with_text_size(theme::TEXT_SIZE_SMALL)
+    .with_text_color(theme::PLACEHOLDER_COLOR)
+    .padding(Insets::uniform_xy(theme::grid(2.0), theme::grid(0.6)))
+}
+
 pub fn detail_widget() -> impl Widget<AppState> {
+    Flex::column()
+        .with_child(download_progress_widget())
+        .with_flex_child(
+            Async::new(
+                utils::spinner_widget,
+                || {
+                    playable::list_widget_with_find(
+                        playable::Display {
+                            track: track::Display {
+                                title: true,
+                                artist: true,
+                                album: true,
+                                cover: true,
+                                ..track::Display::empty()
+                            },
+                        },
+                        cmd::FIND_IN_PLAYLIST,
+                    )
+                },
+                utils::error_widget,
+            )
+            .lens(Ctx::make(
+                AppState::common_ctx,
+                AppState::playlist_detail.then(PlaylistDetail::tracks),
+            )),
+            1.0,
+        )
+        .on_command_async(
+            LOAD_DETAIL,
+            |arg: (PlaylistLink, AppState)| {
+                let d = arg.0;
+                let data = arg.1;
+                sort_playlist(&data, WebApi::global().get_playlist_tracks(&d.id))
+            },
+            |_, data, d| data.playlist_detail.tracks.defer(d.0),
+            |_, data, (d, r)| {
+                let r = r.map(|tracks| PlaylistTracks {
+                    id: d.0.id.clone(),
+                    name: d.0.name.clone(),
+                    tracks,
+                });
+                data.playlist_detail.tracks.update((d.0, r))
+            },
+        )
+}
+
+/// Displays the generated radio queue so it can be browsed like a regular
+/// playlist, once [`PLAYLIST_RADIO`] has finished seeding it.
+pub fn radio_detail_widget() -> impl Widget<AppState> {
     Async::new(
         utils::spinner_widget,
         || {
@@ -171,30 +429,200 @@ pub fn detail_widget() -> impl Widget<AppState> {
         },
         utils::error_widget,
     )
-    .lens(
-        Ctx::make(
-            AppState::common_ctx,
-            AppState::playlist_detail.then(PlaylistDetail::tracks),
-        )
-        .then(Ctx::in_promise()),
-    )
-    .on_command_async(
-        LOAD_DETAIL,
-        |arg: (PlaylistLink, AppState)| {
-            let d = arg.0;
-            let data = arg.1;
-            sort_playlist(&data, WebApi::global().get_playlist_tracks(&d.id))
-        },
-        |_, data, d| data.playlist_detail.tracks.defer(d.0),
-        |_, data, (d, r)| {
-            let r = r.map(|tracks| PlaylistTracks {
-                id: d.0.id.clone(),
-                name: d.0.name.clone(),
-                tracks,
-            });
-            data.playlist_detail.tracks.update((d.0, r))
+    .lens(Ctx::make(AppState::common_ctx, AppState::playlist_radio))
+}
+
+/// Displays a blended playlist's tracks, so the outcome of [`BLEND_PLAYLISTS`]
+/// can be browsed like a regular playlist.
+pub fn blend_detail_widget() -> impl Widget<AppState> {
+    Async::new(
+        utils::spinner_widget,
+        || {
+            playable::list_widget_with_find(
+                playable::Display {
+                    track: track::Display {
+                        title: true,
+                        artist: true,
+                        album: true,
+                        cover: true,
+                        ..track::Display::empty()
+                    },
+                },
+                cmd::FIND_IN_PLAYLIST,
+            )
         },
+        utils::error_widget,
     )
+    .lens(Ctx::make(
+        AppState::common_ctx,
+        AppState::blend_detail.then(PlaylistDetail::tracks),
+    ))
+}
+
+/// Merge two sorted URI lists into their de-duplicated union.
+///
+/// Both inputs must already be sorted. The lists are walked in lockstep with a
+/// two-pointer pass, emitting each URI once and skipping equal neighbours so
+/// duplicates — whether within one list or shared across both — collapse.
+fn merge_sorted(a: &[String], b: &[String]) -> Vec<String> {
+    let mut merged = Vec::with_capacity(a.len() + b.len());
+    let (mut i, mut j) = (0, 0);
+    let mut push = |out: &mut Vec<String>, uri: &String| {
+        if out.last() != Some(uri) {
+            out.push(uri.clone());
+        }
+    };
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            Ordering::Less => {
+                push(&mut merged, &a[i]);
+                i += 1;
+            }
+            Ordering::Greater => {
+                push(&mut merged, &b[j]);
+                j += 1;
+            }
+            Ordering::Equal => {
+                push(&mut merged, &a[i]);
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    for uri in &a[i..] {
+        push(&mut merged, uri);
+    }
+    for uri in &b[j..] {
+        push(&mut merged, uri);
+    }
+    merged
+}
+
+/// Collect a playlist's tracks as a sorted list of Spotify URIs.
+fn sorted_uris(tracks: &Vector<Arc<Track>>) -> Vec<String> {
+    let mut uris: Vec<String> = tracks.iter().filter_map(|t| t.id.0.to_uri()).collect();
+    uris.sort();
+    uris
+}
+
+/// Merge several playlists into a new one holding their de-duplicated union.
+fn merge_playlists(links: &Vector<PlaylistLink>) -> Result<Playlist, Error> {
+    let api = WebApi::global();
+
+    let mut union: Vec<String> = Vec::new();
+    for link in links {
+        let tracks = api.get_playlist_tracks(&link.id)?;
+        union = merge_sorted(&union, &sorted_uris(&tracks));
+    }
+
+    let name = links.iter().map(|l| l.name.as_str()).join(" + ");
+    let playlist = api.create_playlist(&name, false)?;
+    add_tracks_in_batches(&api, &playlist.id, &union)?;
+    Ok(playlist)
+}
+
+/// Add `uris` to `playlist_id` via [`WebApi::add_track_to_playlist`] calls,
+/// grouped [`ADD_TRACK_BATCH`] at a time. Each call adds a single URI, so
+/// there is one request per URI.
+fn add_tracks_in_batches(api: &WebApi, playlist_id: &str, uris: &[String]) -> Result<(), Error> {
+    for batch in uris.chunks(ADD_TRACK_BATCH) {
+        for uri in batch {
+            api.add_track_to_playlist(playlist_id, uri)?;
+        }
+    }
+    Ok(())
+}
+
+/// Interleave two track lists into a single fair blend.
+///
+/// The two lists are walked with independent cursors; at each step the list
+/// that is proportionally *behind* (`posA / n <= posB / m`) contributes the
+/// next track, so a long list is sampled more often than a short one and both
+/// run out at roughly the same point. Tracks shared by both lists are emitted
+/// once, at whichever occurrence the cursors reach first; they are not moved
+/// to their averaged position. The result is capped at `max_len`.
+fn blend_tracks(a: &[Arc<Track>], b: &[Arc<Track>], max_len: usize) -> Vector<Arc<Track>> {
+    let (n, m) = (a.len(), b.len());
+    let mut blended = Vector::new();
+    let mut seen = std::collections::HashSet::new();
+    let (mut i, mut j) = (0, 0);
+
+    let mut emit = |blended: &mut Vector<Arc<Track>>,
+                    seen: &mut std::collections::HashSet<String>,
+                    track: &Arc<Track>| {
+        match track.id.0.to_uri() {
+            Some(uri) if seen.insert(uri) => blended.push_back(track.clone()),
+            None => blended.push_back(track.clone()),
+            _ => {}
+        }
+    };
+
+    while (i < n || j < m) && blended.len() < max_len {
+        let take_a = match (i < n, j < m) {
+            (true, false) => true,
+            (false, true) => false,
+            // Compare fractional progress; cross-multiply to avoid floats.
+            (true, true) => i * m <= j * n,
+            (false, false) => break,
+        };
+        if take_a {
+            emit(&mut blended, &mut seen, &a[i]);
+            i += 1;
+        } else {
+            emit(&mut blended, &mut seen, &b[j]);
+            j += 1;
+        }
+    }
+    blended
+}
+
+/// Build a blended playlist from two sources, persist it via the Web API, and
+/// return the blended tracks alongside it so the caller can populate
+/// [`AppState::blend_detail`] without re-fetching what it already has.
+fn blend_playlists(
+    links: &(PlaylistLink, PlaylistLink),
+) -> Result<(Playlist, Vector<Arc<Track>>), Error> {
+    let api = WebApi::global();
+    let a = api.get_playlist_tracks(&links.0.id)?;
+    let b = api.get_playlist_tracks(&links.1.id)?;
+
+    let blended = blend_tracks(
+        &a.iter().cloned().collect::<Vec<_>>(),
+        &b.iter().cloned().collect::<Vec<_>>(),
+        BLEND_MAX_LEN,
+    );
+
+    let name = format!("{} × {}", links.0.name, links.1.name);
+    let playlist = api.create_playlist(&name, false)?;
+    let uris: Vec<String> = blended.iter().filter_map(|t| t.id.0.to_uri()).collect();
+    add_tracks_in_batches(&api, &playlist.id, &uris)?;
+    Ok((playlist, blended))
+}
+
+/// Compare two tracks by `criteria`, breaking ties on Artist → Album → Title
+/// so the order is deterministic and musically sensible instead of arbitrary.
+///
+/// `order` flips the whole comparison, tie-break included, so a descending
+/// sort shows same-criterion tracks in descending Artist → Album → Title
+/// order too rather than always falling back to ascending.
+fn compare_tracks(a: &Track, b: &Track, criteria: SortCriteria, order: SortOrder) -> Ordering {
+    let ordering = match criteria {
+        SortCriteria::Title => a.name.cmp(&b.name),
+        SortCriteria::Artist => a.artist_name().cmp(&b.artist_name()),
+        SortCriteria::Album => a.album_name().cmp(&b.album_name()),
+        SortCriteria::Duration => a.duration.cmp(&b.duration),
+        SortCriteria::DateAdded => a.added_at.cmp(&b.added_at),
+        SortCriteria::Popularity => a.popularity.cmp(&b.popularity),
+    }
+    .then_with(|| a.artist_name().cmp(&b.artist_name()))
+    .then_with(|| a.album_name().cmp(&b.album_name()))
+    .then_with(|| a.name.cmp(&b.name));
+
+    if order == SortOrder::Descending {
+        ordering.reverse()
+    } else {
+        ordering
+    }
 }
 
 fn sort_playlist(
@@ -206,32 +634,11 @@ fn sort_playlist(
 
     let playlist = result.unwrap_or_else(|_| Vector::new());
 
-    let mut sorted_playlist: Vector<Arc<Track>> = playlist
+    let sorted_playlist: Vector<Arc<Track>> = playlist
         .into_iter()
-        .sorted_by(|a, b| {
-            let mut method = match sort_criteria {
-                SortCriteria::Title => a.name.cmp(&b.name),
-                SortCriteria::Artist => a.artist_name().cmp(&b.artist_name()),
-                SortCriteria::Album => a.album_name().cmp(&b.album_name()),
-                SortCriteria::Duration => a.duration.cmp(&b.duration),
-                _ => Ordering::Equal,
-            };
-            method = if sort_order == SortOrder::Descending {
-                method.reverse()
-            } else {
-                method
-            };
-            method
-        })
+        .sorted_by(|a, b| compare_tracks(a, b, sort_criteria, sort_order))
         .collect();
 
-    sorted_playlist =
-        if sort_criteria == SortCriteria::DateAdded && sort_order == SortOrder::Descending {
-            sorted_playlist.into_iter().rev().collect()
-        } else {
-            sorted_playlist
-        };
-
     Ok(sorted_playlist)
 }
 
@@ -245,5 +652,152 @@ fn playlist_menu(playlist: &Playlist) -> Menu<AppState> {
         .command(cmd::COPY.with(playlist.url())),
     );
 
+    menu = menu.entry({
+        let link = playlist.link();
+        MenuItem::new(
+            LocalizedString::new("menu-item-download-playlist")
+                .with_placeholder("Download for Offline Listening"),
+        )
+        .command(DOWNLOAD_PLAYLIST.with(playlist.link()))
+        // Guard against queuing a second download for a playlist that's
+        // already downloading.
+        .enabled_if(move |data: &AppState, _env| !data.library.is_downloading(&link))
+    });
+
+    menu = menu.entry(
+        MenuItem::new(
+            LocalizedString::new("menu-item-playlist-radio").with_placeholder("Start Radio"),
+        )
+        .command(PLAYLIST_RADIO.with(playlist.link())),
+    );
+
+    menu = menu.entry(
+        MenuItem::new(
+            LocalizedString::new("menu-item-toggle-playlist-selected")
+                .with_placeholder("Select for Merge/Blend"),
+        )
+        .command(TOGGLE_PLAYLIST_SELECTED.with(playlist.link())),
+    );
+
     menu
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::data::{SpotifyId, TrackId};
+
+    use super::*;
+
+    fn track(id: u64) -> Arc<Track> {
+        named_track(id, &format!("Artist {id}"), &format!("Album {id}"), &format!("Track {id}"))
+    }
+
+    fn named_track(id: u64, artist: &str, album: &str, title: &str) -> Arc<Track> {
+        Arc::new(Track {
+            id: TrackId(SpotifyId(id)),
+            name: title.into(),
+            artists: Vector::unit(artist.into()),
+            album_name: album.into(),
+            duration: std::time::Duration::from_secs(180),
+            added_at: chrono::Utc::now(),
+            popularity: 0,
+        })
+    }
+
+    fn uri(id: u64) -> String {
+        TrackId(SpotifyId(id)).0.to_uri().unwrap()
+    }
+
+    #[test]
+    fn merge_sorted_unions_and_dedups() {
+        let a = vec![uri(1), uri(2), uri(2), uri(4)];
+        let b = vec![uri(2), uri(3)];
+        assert_eq!(merge_sorted(&a, &b), vec![uri(1), uri(2), uri(3), uri(4)]);
+    }
+
+    #[test]
+    fn merge_sorted_handles_empty_inputs() {
+        let a = vec![uri(1), uri(2)];
+        assert_eq!(merge_sorted(&a, &[]), a);
+        assert_eq!(merge_sorted(&[], &a), a);
+        assert_eq!(merge_sorted(&[], &[]), Vec::<String>::new());
+    }
+
+    #[test]
+    fn sorted_uris_sorts_and_keeps_duplicates() {
+        let tracks: Vector<Arc<Track>> = vec![track(3), track(1), track(2), track(1)].into();
+        assert_eq!(sorted_uris(&tracks), vec![uri(1), uri(1), uri(2), uri(3)]);
+    }
+
+    #[test]
+    fn blend_tracks_interleaves_by_proportion() {
+        let a: Vec<_> = (1..=4).map(track).collect();
+        let b: Vec<_> = (101..=101 + 1).map(track).collect();
+        let blended = blend_tracks(&a, &b, 100);
+        // `a` is twice as long as `b`, so it contributes two tracks for every
+        // one from `b`, and both lists are exhausted in the result.
+        let ids: Vec<u64> = blended.iter().map(|t| t.id.0 .0).collect();
+        assert_eq!(ids.len(), 6);
+        assert!(ids.contains(&101) && ids.contains(&102));
+        assert_eq!(ids, vec![1, 101, 2, 3, 102, 4]);
+    }
+
+    #[test]
+    fn blend_tracks_dedups_shared_tracks_and_caps_length() {
+        let a: Vec<_> = (1..=5).map(track).collect();
+        let b: Vec<_> = (3..=7).map(track).collect();
+        let blended = blend_tracks(&a, &b, 3);
+        assert_eq!(blended.len(), 3);
+
+        let blended_all = blend_tracks(&a, &b, 100);
+        let mut ids: Vec<u64> = blended_all.iter().map(|t| t.id.0 .0).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), blended_all.len());
+    }
+
+    #[test]
+    fn compare_tracks_breaks_ties_by_artist_album_title() {
+        // All three share the same Duration (the chosen criterion), so the
+        // outcome depends entirely on the Artist → Album → Title fallback.
+        let mut tracks = vec![
+            named_track(1, "B Artist", "Z Album", "Z Title"),
+            named_track(2, "A Artist", "Z Album", "A Title"),
+            named_track(3, "A Artist", "A Album", "Z Title"),
+        ];
+        // Track 3 (A/A/Z) < Track 2 (A/Z/A) < Track 1 (B/Z/Z), entirely from
+        // the Artist → Album → Title fallback since all three tie on Duration.
+        for t in &mut tracks {
+            Arc::get_mut(t).unwrap().duration = std::time::Duration::from_secs(200);
+        }
+
+        tracks.sort_by(|a, b| {
+            compare_tracks(a, b, SortCriteria::Duration, SortOrder::Ascending)
+        });
+
+        let ids: Vec<u64> = tracks.iter().map(|t| t.id.0 .0).collect();
+        assert_eq!(ids, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn compare_tracks_descending_reverses_the_tie_break_too() {
+        // Same tracks as `compare_tracks_breaks_ties_by_artist_album_title`,
+        // but sorted descending: the Artist → Album → Title fallback should
+        // flip along with the primary criterion, not stay ascending.
+        let mut tracks = vec![
+            named_track(1, "B Artist", "Z Album", "Z Title"),
+            named_track(2, "A Artist", "Z Album", "A Title"),
+            named_track(3, "A Artist", "A Album", "Z Title"),
+        ];
+        for t in &mut tracks {
+            Arc::get_mut(t).unwrap().duration = std::time::Duration::from_secs(200);
+        }
+
+        tracks.sort_by(|a, b| {
+            compare_tracks(a, b, SortCriteria::Duration, SortOrder::Descending)
+        });
+
+        let ids: Vec<u64> = tracks.iter().map(|t| t.id.0 .0).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+    }
+}